@@ -0,0 +1,161 @@
+use crate::menu::items::{label_layout, sized_bounds};
+use crate::menu::MenuStyle;
+use core::cell::Cell;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::prelude::Primitive;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+#[derive(PartialEq, Clone, Copy)]
+pub struct CheckboxItem<'a, C>
+where
+    C: PixelColor,
+{
+    label: &'static str,
+    checked: bool,
+    inactive: bool,
+    highlighted: Cell<bool>,
+    position: Point,
+    menu_style: MenuStyle<'a, C>,
+}
+
+impl<C> CheckboxItem<'_, C>
+where
+    C: PixelColor,
+{
+    pub const fn new<'a>(label: &'static str, menu_style: MenuStyle<'a, C>) -> CheckboxItem<'a, C> {
+        CheckboxItem {
+            label,
+            checked: false,
+            inactive: false,
+            highlighted: Cell::new(false),
+            position: Point::zero(),
+            menu_style,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// Current checked state of this checkbox.
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Flips the checked state, as happens when the item is selected.
+    pub fn toggle(&mut self) {
+        self.checked = !self.checked;
+    }
+
+    /// Whether this item is disabled and skipped by navigation; see `MenuItems::set_inactive`.
+    pub fn is_inactive(&self) -> bool {
+        self.inactive
+    }
+
+    pub fn set_inactive(&mut self, inactive: bool) {
+        self.inactive = inactive;
+    }
+
+    pub fn set_highlighted(&self, highlighted: bool) {
+        self.highlighted.set(highlighted);
+    }
+}
+
+impl<C> Debug for CheckboxItem<'_, C>
+where
+    C: PixelColor,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Checkbox]", self.label)
+    }
+}
+
+impl<C> Display for CheckboxItem<'_, C>
+where
+    C: PixelColor,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C> View for CheckboxItem<'_, C>
+where
+    C: PixelColor,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        let measured = self
+            .menu_style
+            .item_character_style
+            .measure_string(self.label, Point::zero(), Baseline::Bottom)
+            .bounding_box;
+        sized_bounds(measured, self.menu_style.item_height)
+    }
+}
+
+impl<C> Drawable for CheckboxItem<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let character_style = if self.highlighted.get() {
+            display
+                .bounding_box()
+                .into_styled(PrimitiveStyle::with_fill(
+                    self.menu_style.highlight_item_color,
+                ))
+                .draw(display)?;
+            self.menu_style.highlight_text_style
+        } else {
+            self.menu_style.item_character_style
+        };
+
+        let (label_position, vertical_offset) = label_layout(
+            self.menu_style.item_character_style,
+            self.label,
+            self.position,
+            display.bounding_box().size().height,
+        );
+
+        Text::with_baseline(self.label, label_position, character_style, Baseline::Top)
+            .draw(display)?;
+
+        let mut indicator_style = self.menu_style.item_character_style;
+        indicator_style.text_color = Some(self.menu_style.indicator_fill_color);
+
+        let indicator = if self.checked { "[x]" } else { "[ ]" };
+        Text::with_text_style(
+            indicator,
+            Point::new(
+                display.bounding_box().size().width as i32,
+                vertical_offset,
+            ),
+            indicator_style,
+            TextStyleBuilder::new()
+                .alignment(Alignment::Right)
+                .baseline(Baseline::Top)
+                .build(),
+        )
+        .draw(display)?;
+
+        Ok(())
+    }
+}
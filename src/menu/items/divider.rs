@@ -0,0 +1,107 @@
+use crate::menu::items::sized_bounds;
+use crate::menu::MenuStyle;
+use core::cell::Cell;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Point, Size};
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::prelude::Primitive;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// Thickness, in pixels, of the horizontal rule a divider draws.
+const DIVIDER_THICKNESS: u32 = 2;
+
+/// A thin horizontal rule, used to visually group items without being selectable.
+#[derive(PartialEq, Clone, Copy)]
+pub struct DividerItem<'a, C>
+where
+    C: PixelColor,
+{
+    highlighted: Cell<bool>,
+    position: Point,
+    menu_style: MenuStyle<'a, C>,
+}
+
+impl<C> DividerItem<'_, C>
+where
+    C: PixelColor,
+{
+    pub const fn new<'a>(menu_style: MenuStyle<'a, C>) -> DividerItem<'a, C> {
+        DividerItem {
+            highlighted: Cell::new(false),
+            position: Point::zero(),
+            menu_style,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        ""
+    }
+
+    pub fn set_highlighted(&self, highlighted: bool) {
+        self.highlighted.set(highlighted);
+    }
+}
+
+impl<C> Debug for DividerItem<'_, C>
+where
+    C: PixelColor,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[Divider]")
+    }
+}
+
+impl<C> Display for DividerItem<'_, C>
+where
+    C: PixelColor,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "")
+    }
+}
+
+impl<C> View for DividerItem<'_, C>
+where
+    C: PixelColor,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        let measured = Rectangle::new(Point::zero(), Size::new(0, DIVIDER_THICKNESS));
+        sized_bounds(measured, self.menu_style.item_height)
+    }
+}
+
+impl<C> Drawable for DividerItem<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let display_size = display.bounding_box();
+        let line_y = (display_size.size().height.saturating_sub(DIVIDER_THICKNESS) / 2) as i32;
+        let line_position = Point::new(self.position.x, self.position.y + line_y);
+
+        Rectangle::new(
+            line_position,
+            Size::new(display_size.size().width, DIVIDER_THICKNESS),
+        )
+        .into_styled(PrimitiveStyle::with_fill(
+            self.menu_style.indicator_fill_color,
+        ))
+        .draw(display)?;
+
+        Ok(())
+    }
+}
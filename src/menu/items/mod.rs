@@ -0,0 +1,190 @@
+pub mod checkbox;
+pub mod divider;
+pub mod multi_option;
+pub mod radio;
+pub mod section;
+pub mod submenu;
+
+use crate::menu::items::checkbox::CheckboxItem;
+use crate::menu::items::divider::DividerItem;
+use crate::menu::items::multi_option::MultiOptionItem;
+use crate::menu::items::radio::RadioItem;
+use crate::menu::items::section::SectionItem;
+use crate::menu::items::submenu::SubmenuItem;
+use crate::menu::ItemHeight;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Point, Size};
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::Baseline;
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// Behaviour common to every concrete menu item type.
+pub trait MenuItem {
+    fn label(&self) -> &str;
+}
+
+/// Applies a [`MenuStyle`](crate::menu::MenuStyle)'s configured `item_height` to a label's
+/// measured bounding box, as consulted by every item's `View::bounds` impl.
+pub(crate) fn sized_bounds(measured: Rectangle, item_height: ItemHeight) -> Rectangle {
+    let height = match item_height {
+        ItemHeight::Dynamic => measured.size.height,
+        ItemHeight::Uniform(min_height) => measured.size.height.max(min_height),
+        ItemHeight::Static(height) => height,
+    };
+    Rectangle::new(measured.top_left, Size::new(measured.size.width, height))
+}
+
+/// Vertical pixel offset that centres content of `content_height` within a row of `row_height`.
+pub(crate) fn vertical_text_offset(content_height: u32, row_height: u32) -> i32 {
+    (row_height.saturating_sub(content_height) / 2) as i32
+}
+
+/// Measures `label` under `character_style` and centres it vertically within a row of
+/// `row_height`, as every item's `Drawable::draw` impl needs for its label and, where
+/// applicable, its indicator text. Returns the label's draw position (offset from `position`)
+/// and the raw vertical offset for positioning indicator text alongside it.
+pub(crate) fn label_layout<C: PixelColor>(
+    character_style: MonoTextStyle<'_, C>,
+    label: &str,
+    position: Point,
+    row_height: u32,
+) -> (Point, i32) {
+    let content_height = character_style
+        .measure_string(label, Point::zero(), Baseline::Bottom)
+        .bounding_box
+        .size
+        .height;
+    let vertical_offset = vertical_text_offset(content_height, row_height);
+    (
+        Point::new(position.x, position.y + vertical_offset),
+        vertical_offset,
+    )
+}
+
+#[derive(Clone, Copy)]
+pub enum MenuItems<'a, C>
+where
+    C: PixelColor,
+{
+    Submenu(SubmenuItem<'a, C>),
+    Checkbox(CheckboxItem<'a, C>),
+    Radio(RadioItem<'a, C>),
+    Selector(MultiOptionItem<'a, C>),
+    Section(SectionItem<'a, C>),
+    Divider(DividerItem<'a, C>),
+}
+
+impl<C> MenuItem for MenuItems<'_, C>
+where
+    C: PixelColor,
+{
+    fn label(&self) -> &str {
+        match self {
+            MenuItems::Submenu(item) => item.label(),
+            MenuItems::Checkbox(item) => item.label(),
+            MenuItems::Radio(item) => item.label(),
+            MenuItems::Selector(item) => item.label(),
+            MenuItems::Section(item) => item.label(),
+            MenuItems::Divider(item) => item.label(),
+        }
+    }
+}
+
+impl<C> MenuItems<'_, C>
+where
+    C: PixelColor,
+{
+    /// Marks whether this item is the currently selected row, so its `Drawable` impl renders
+    /// the selection background and `highlight_text_style`.
+    pub(crate) fn set_highlighted(&self, highlighted: bool) {
+        match self {
+            MenuItems::Submenu(item) => item.set_highlighted(highlighted),
+            MenuItems::Checkbox(item) => item.set_highlighted(highlighted),
+            MenuItems::Radio(item) => item.set_highlighted(highlighted),
+            MenuItems::Selector(item) => item.set_highlighted(highlighted),
+            MenuItems::Section(item) => item.set_highlighted(highlighted),
+            MenuItems::Divider(item) => item.set_highlighted(highlighted),
+        }
+    }
+
+    /// Whether navigation may land the cursor on this item. Sections and dividers are always
+    /// non-selectable; other item types are non-selectable while their `inactive` flag is set.
+    pub(crate) fn is_selectable(&self) -> bool {
+        match self {
+            MenuItems::Submenu(item) => !item.is_inactive(),
+            MenuItems::Checkbox(item) => !item.is_inactive(),
+            MenuItems::Radio(item) => !item.is_inactive(),
+            MenuItems::Selector(item) => !item.is_inactive(),
+            MenuItems::Section(_) => false,
+            MenuItems::Divider(_) => false,
+        }
+    }
+
+    /// Sets the `inactive` flag consulted by `is_selectable`, for every item type that has one.
+    /// Sections and dividers have no such flag and are always non-selectable regardless.
+    /// Rendering currently falls back to `item_character_style` for inactive items; a dedicated
+    /// disabled text color could be added to `MenuStyle`.
+    pub(crate) fn set_inactive(&mut self, inactive: bool) {
+        match self {
+            MenuItems::Submenu(item) => item.set_inactive(inactive),
+            MenuItems::Checkbox(item) => item.set_inactive(inactive),
+            MenuItems::Radio(item) => item.set_inactive(inactive),
+            MenuItems::Selector(item) => item.set_inactive(inactive),
+            MenuItems::Section(_) => {}
+            MenuItems::Divider(_) => {}
+        }
+    }
+}
+
+impl<C> View for MenuItems<'_, C>
+where
+    C: PixelColor,
+{
+    fn translate_impl(&mut self, by: Point) {
+        match self {
+            MenuItems::Submenu(item) => item.translate_impl(by),
+            MenuItems::Checkbox(item) => item.translate_impl(by),
+            MenuItems::Radio(item) => item.translate_impl(by),
+            MenuItems::Selector(item) => item.translate_impl(by),
+            MenuItems::Section(item) => item.translate_impl(by),
+            MenuItems::Divider(item) => item.translate_impl(by),
+        }
+    }
+
+    fn bounds(&self) -> Rectangle {
+        match self {
+            MenuItems::Submenu(item) => item.bounds(),
+            MenuItems::Checkbox(item) => item.bounds(),
+            MenuItems::Radio(item) => item.bounds(),
+            MenuItems::Selector(item) => item.bounds(),
+            MenuItems::Section(item) => item.bounds(),
+            MenuItems::Divider(item) => item.bounds(),
+        }
+    }
+}
+
+impl<C> Drawable for MenuItems<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        match self {
+            MenuItems::Submenu(item) => item.draw(display),
+            MenuItems::Checkbox(item) => item.draw(display),
+            MenuItems::Radio(item) => item.draw(display),
+            MenuItems::Selector(item) => item.draw(display),
+            MenuItems::Section(item) => item.draw(display),
+            MenuItems::Divider(item) => item.draw(display),
+        }
+    }
+}
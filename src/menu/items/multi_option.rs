@@ -0,0 +1,181 @@
+use crate::menu::items::{label_layout, sized_bounds};
+use crate::menu::MenuStyle;
+use core::cell::Cell;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::prelude::Primitive;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+#[derive(PartialEq, Clone, Copy)]
+pub struct MultiOptionItem<'a, C>
+where
+    C: PixelColor,
+{
+    label: &'static str,
+    options: &'a [&'static str],
+    selected_index: usize,
+    inactive: bool,
+    highlighted: Cell<bool>,
+    position: Point,
+    menu_style: MenuStyle<'a, C>,
+}
+
+impl<'a, C> MultiOptionItem<'a, C>
+where
+    C: PixelColor,
+{
+    pub const fn new(
+        label: &'static str,
+        menu_style: MenuStyle<'a, C>,
+        options: &'a [&'static str],
+    ) -> MultiOptionItem<'a, C> {
+        MultiOptionItem {
+            label,
+            options,
+            selected_index: 0,
+            inactive: false,
+            highlighted: Cell::new(false),
+            position: Point::zero(),
+            menu_style,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// The option currently shown alongside the label.
+    pub fn selected_option(&self) -> &'static str {
+        self.options.get(self.selected_index).copied().unwrap_or("")
+    }
+
+    /// Moves to the previous option, wrapping around to the last one.
+    pub fn select_previous(&mut self) {
+        if self.options.is_empty() {
+            return;
+        }
+        self.selected_index = if self.selected_index == 0 {
+            self.options.len() - 1
+        } else {
+            self.selected_index - 1
+        };
+    }
+
+    /// Moves to the next option, wrapping around to the first one.
+    pub fn select_next(&mut self) {
+        if self.options.is_empty() {
+            return;
+        }
+        self.selected_index = (self.selected_index + 1) % self.options.len();
+    }
+
+    /// Whether this item is disabled and skipped by navigation; see `MenuItems::set_inactive`.
+    pub fn is_inactive(&self) -> bool {
+        self.inactive
+    }
+
+    pub fn set_inactive(&mut self, inactive: bool) {
+        self.inactive = inactive;
+    }
+
+    pub fn set_highlighted(&self, highlighted: bool) {
+        self.highlighted.set(highlighted);
+    }
+}
+
+impl<C> Debug for MultiOptionItem<'_, C>
+where
+    C: PixelColor,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Selector]", self.label)
+    }
+}
+
+impl<C> Display for MultiOptionItem<'_, C>
+where
+    C: PixelColor,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C> View for MultiOptionItem<'_, C>
+where
+    C: PixelColor,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        let measured = self
+            .menu_style
+            .item_character_style
+            .measure_string(self.label, Point::zero(), Baseline::Bottom)
+            .bounding_box;
+        sized_bounds(measured, self.menu_style.item_height)
+    }
+}
+
+impl<C> Drawable for MultiOptionItem<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let character_style = if self.highlighted.get() {
+            display
+                .bounding_box()
+                .into_styled(PrimitiveStyle::with_fill(
+                    self.menu_style.highlight_item_color,
+                ))
+                .draw(display)?;
+            self.menu_style.highlight_text_style
+        } else {
+            self.menu_style.item_character_style
+        };
+
+        let (label_position, vertical_offset) = label_layout(
+            self.menu_style.item_character_style,
+            self.label,
+            self.position,
+            display.bounding_box().size().height,
+        );
+
+        Text::with_baseline(self.label, label_position, character_style, Baseline::Top)
+            .draw(display)?;
+
+        let mut option_style = self.menu_style.item_character_style;
+        option_style.text_color = Some(self.menu_style.indicator_fill_color);
+
+        Text::with_text_style(
+            self.selected_option(),
+            Point::new(
+                display.bounding_box().size().width as i32,
+                vertical_offset,
+            ),
+            option_style,
+            TextStyleBuilder::new()
+                .alignment(Alignment::Right)
+                .baseline(Baseline::Top)
+                .build(),
+        )
+        .draw(display)?;
+
+        Ok(())
+    }
+}
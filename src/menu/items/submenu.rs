@@ -1,4 +1,6 @@
+use crate::menu::items::{label_layout, sized_bounds};
 use crate::menu::MenuStyle;
+use core::cell::Cell;
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
 use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt};
@@ -17,7 +19,8 @@ where
     C: PixelColor,
 {
     label: &'static str,
-    highlighted: bool,
+    inactive: bool,
+    highlighted: Cell<bool>,
     position: Point,
     menu_style: MenuStyle<'a, C>,
 }
@@ -29,7 +32,8 @@ where
     pub const fn new<'a>(label: &'static str, menu_style: MenuStyle<'a, C>) -> SubmenuItem<'a, C> {
         SubmenuItem {
             label,
-            highlighted: false,
+            inactive: false,
+            highlighted: Cell::new(false),
             position: Point::zero(),
             menu_style,
         }
@@ -38,6 +42,19 @@ where
     pub fn label(&self) -> &'static str {
         self.label
     }
+
+    /// Whether this item is disabled and skipped by navigation; see `MenuItems::set_inactive`.
+    pub fn is_inactive(&self) -> bool {
+        self.inactive
+    }
+
+    pub fn set_inactive(&mut self, inactive: bool) {
+        self.inactive = inactive;
+    }
+
+    pub fn set_highlighted(&self, highlighted: bool) {
+        self.highlighted.set(highlighted);
+    }
 }
 
 impl<C> Debug for SubmenuItem<'_, C>
@@ -67,10 +84,12 @@ where
     }
 
     fn bounds(&self) -> Rectangle {
-        self.menu_style
+        let measured = self
+            .menu_style
             .item_character_style
             .measure_string(self.label, Point::zero(), Baseline::Bottom)
-            .bounding_box
+            .bounding_box;
+        sized_bounds(measured, self.menu_style.item_height)
     }
 }
 
@@ -90,6 +109,18 @@ where
         let submenu_indicator_size = Size::new(self.size().height / 2, self.size().height);
 
         let display_size = display.bounding_box();
+
+        let character_style = if self.highlighted.get() {
+            display_size
+                .into_styled(PrimitiveStyle::with_fill(
+                    self.menu_style.highlight_item_color,
+                ))
+                .draw(display)?;
+            self.menu_style.highlight_text_style
+        } else {
+            self.menu_style.item_character_style
+        };
+
         let submenu_indicator_draw_area =
             display_size.resized_width(submenu_indicator_size.width, AnchorX::Right);
         let mut indicator_display = display.cropped(&submenu_indicator_draw_area);
@@ -116,13 +147,15 @@ where
         );
         let mut label_display = display.cropped(&submenu_label_draw_area);
 
-        Text::with_baseline(
+        let (label_position, _) = label_layout(
+            self.menu_style.item_character_style,
             self.label,
             self.position,
-            self.menu_style.item_character_style,
-            Baseline::Top,
-        )
-        .draw(&mut label_display)?;
+            display_size.size().height,
+        );
+
+        Text::with_baseline(self.label, label_position, character_style, Baseline::Top)
+            .draw(&mut label_display)?;
 
         Ok(())
     }
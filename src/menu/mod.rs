@@ -3,18 +3,28 @@
 use crate::menu::items::MenuItem;
 pub mod items;
 
+use core::cell::Cell;
+
 use crate::menu::items::checkbox::CheckboxItem;
+use crate::menu::items::divider::DividerItem;
 use crate::menu::items::multi_option::MultiOptionItem;
+use crate::menu::items::radio::RadioItem;
 use crate::menu::items::section::SectionItem;
 use crate::menu::items::submenu::SubmenuItem;
 use crate::menu::items::MenuItems;
-use embedded_graphics::geometry::AnchorY;
+use embedded_graphics::draw_target::DrawTargetExt;
+use embedded_graphics::geometry::{AnchorX, AnchorY};
 use embedded_graphics::mono_font::MonoTextStyle;
 use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Triangle};
 use embedded_graphics::text::renderer::TextRenderer;
 use embedded_graphics::text::{Baseline, Text};
 use embedded_layout::View;
-use trees::Tree;
+use trees::{Node, Tree};
+
+/// Width, in pixels, reserved on the right edge of the item viewport for the
+/// scroll-position indicator triangles, so they never overlap item text.
+const SCROLL_INDICATOR_WIDTH: u32 = 6;
 
 pub struct Menu<'a, C>
 where
@@ -25,6 +35,53 @@ where
     menu_state: MenuState,
 }
 
+/// Identifies an item returned by `add_checkbox`/`add_radio`/`add_selector`/`add_submenu`, so it
+/// can be read back or reconfigured (e.g. via `Menu::checkbox`, `Menu::set_inactive`) after
+/// construction instead of being write-only.
+///
+/// Stores the full path of child indices from the `Menu` that returned it down to the item,
+/// mirroring `MenuState::path`, rather than a single flat index into that `Menu`'s direct
+/// children. A flat index would silently resolve against the wrong `Menu` (or nothing at all)
+/// once a sub-`Menu` built with its own handles is nested into a parent via `add_submenu`, since
+/// the sub-`Menu` itself is consumed by that call. Use `nested_under` to rebase a handle obtained
+/// from a sub-`Menu` before nesting so it keeps resolving correctly against the parent afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemHandle {
+    path: [usize; MAX_MENU_DEPTH],
+    len: usize,
+}
+
+impl ItemHandle {
+    fn leaf(index: usize) -> Self {
+        let mut path = [0; MAX_MENU_DEPTH];
+        path[0] = index;
+        Self { path, len: 1 }
+    }
+
+    fn as_slice(&self) -> &[usize] {
+        &self.path[..self.len]
+    }
+
+    /// Rebases a handle returned by calls made against a sub-`Menu` *before* it was nested, so it
+    /// resolves correctly against the parent it was nested into. `submenu` is the handle the
+    /// parent's own `add_submenu` call returned for that sub-`Menu`.
+    ///
+    /// Panics if the combined depth would exceed `MAX_MENU_DEPTH`.
+    pub fn nested_under(mut self, submenu: ItemHandle) -> ItemHandle {
+        let combined_len = submenu.len + self.len;
+        assert!(
+            combined_len <= MAX_MENU_DEPTH,
+            "ItemHandle nesting exceeds MAX_MENU_DEPTH"
+        );
+        let mut path = [0; MAX_MENU_DEPTH];
+        path[..submenu.len].copy_from_slice(&submenu.path[..submenu.len]);
+        path[submenu.len..combined_len].copy_from_slice(&self.path[..self.len]);
+        self.path = path;
+        self.len = combined_len;
+        self
+    }
+}
+
 impl<'a, C> Menu<'a, C>
 where
     C: PixelColor,
@@ -37,51 +94,245 @@ where
         }
     }
 
-    /// Add menu item to the menu structure that will be drawn
-    pub fn add_item(&mut self, item: MenuItems<'a, C>) {
+    /// Add menu item to the menu structure that will be drawn, returning a handle that can be
+    /// used to read or reconfigure it later.
+    pub fn add_item(&mut self, item: MenuItems<'a, C>) -> ItemHandle {
         self.menu_tree_root.push_back(Tree::new(item));
-        self.menu_state
-            .update_item_count(self.menu_tree_root.iter().count());
+        ItemHandle::leaf(self.menu_tree_root.iter().count() - 1)
     }
 
     /// Add checkbox as next item in the menu
-    pub fn add_checkbox(&mut self, label: &'static str) {
+    pub fn add_checkbox(&mut self, label: &'static str) -> ItemHandle {
         self.add_item(MenuItems::Checkbox(CheckboxItem::new(
             label,
             self.menu_style,
-        )));
+        )))
     }
 
     /// Add selector as next item in the menu
-    pub fn add_selector(&mut self, label: &'static str, options: &'a [&'static str]) {
+    pub fn add_selector(&mut self, label: &'static str, options: &'a [&'static str]) -> ItemHandle {
         self.add_item(MenuItems::Selector(MultiOptionItem::new(
             label,
             self.menu_style,
             options,
-        )));
+        )))
+    }
+
+    /// Add a radio button to the menu, mutually exclusive with other radios sharing `group`
+    pub fn add_radio(&mut self, label: &'static str, group: usize) -> ItemHandle {
+        self.add_item(MenuItems::Radio(RadioItem::new(label, group, self.menu_style)))
     }
 
     /// Add section (non-selectable item) as next item in the menu
-    pub fn add_section(&mut self, label: &'static str) {
-        self.add_item(MenuItems::Section(SectionItem::new(label, self.menu_style)));
+    pub fn add_section(&mut self, label: &'static str) -> ItemHandle {
+        self.add_item(MenuItems::Section(SectionItem::new(label, self.menu_style)))
     }
 
-    /// Add a sub-menu to the menu structure that will be drawn
-    pub fn add_submenu(&mut self, submenu: Menu<'a, C>) {
+    /// Add a divider (non-selectable horizontal rule) as next item in the menu
+    pub fn add_divider(&mut self) -> ItemHandle {
+        self.add_item(MenuItems::Divider(DividerItem::new(self.menu_style)))
+    }
+
+    /// Add a sub-menu to the menu structure that will be drawn. The returned handle identifies
+    /// the submenu item itself; pass it to `ItemHandle::nested_under` to rebase a handle obtained
+    /// from `submenu` before this call, so it can still be used to query the nested item.
+    pub fn add_submenu(&mut self, submenu: Menu<'a, C>) -> ItemHandle {
         self.menu_tree_root.push_back(submenu.into());
-        self.menu_state
-            .update_item_count(self.menu_tree_root.iter().count());
+        ItemHandle::leaf(self.menu_tree_root.iter().count() - 1)
+    }
+
+    /// Walks `handle`'s path of child indices from the root, so a handle keeps resolving
+    /// correctly against whichever `Menu` now owns the item, regardless of `add_submenu` nesting.
+    fn resolve(&self, handle: ItemHandle) -> Option<&Node<MenuItems<'a, C>>> {
+        let mut node: &Node<MenuItems<'a, C>> = &self.menu_tree_root;
+        for &index in handle.as_slice() {
+            node = node.iter().nth(index)?;
+        }
+        Some(node)
     }
 
+    fn resolve_mut(&mut self, handle: ItemHandle) -> Option<&mut Node<MenuItems<'a, C>>> {
+        let mut node: &mut Node<MenuItems<'a, C>> = &mut self.menu_tree_root;
+        for &index in handle.as_slice() {
+            node = node.iter_mut().nth(index)?;
+        }
+        Some(node)
+    }
+
+    /// Looks up the checkbox added via `add_checkbox` for `handle`, so its `is_checked` state can
+    /// be read back after navigation. Returns `None` if `handle` doesn't refer to a checkbox.
+    pub fn checkbox(&self, handle: ItemHandle) -> Option<&CheckboxItem<'a, C>> {
+        match self.resolve(handle).map(|node| node.data()) {
+            Some(MenuItems::Checkbox(item)) => Some(item),
+            _ => None,
+        }
+    }
+
+    /// Looks up the radio button added via `add_radio` for `handle`, so its `is_checked` state can
+    /// be read back after navigation. Returns `None` if `handle` doesn't refer to a radio button.
+    pub fn radio(&self, handle: ItemHandle) -> Option<&RadioItem<'a, C>> {
+        match self.resolve(handle).map(|node| node.data()) {
+            Some(MenuItems::Radio(item)) => Some(item),
+            _ => None,
+        }
+    }
+
+    /// Looks up the selector added via `add_selector` for `handle`, so its `selected_option` can
+    /// be read back after navigation. Returns `None` if `handle` doesn't refer to a selector.
+    pub fn selector(&self, handle: ItemHandle) -> Option<&MultiOptionItem<'a, C>> {
+        match self.resolve(handle).map(|node| node.data()) {
+            Some(MenuItems::Selector(item)) => Some(item),
+            _ => None,
+        }
+    }
+
+    /// Enables or disables the item identified by `handle`, per `MenuItems::set_inactive`.
+    /// Disabled items are skipped by `navigate_up`/`navigate_down`. Does nothing if `handle`
+    /// refers to a section or divider, neither of which can ever be selectable.
+    pub fn set_inactive(&mut self, handle: ItemHandle, inactive: bool) {
+        if let Some(node) = self.resolve_mut(handle) {
+            node.data_mut().set_inactive(inactive);
+        }
+    }
+
+    /// Moves the highlight down, skipping past any dividers/sections/inactive items.
     pub fn navigate_down(&mut self) {
-        self.menu_state.move_down();
+        let item_count = self.current_node().iter().count();
+        for _ in 0..item_count {
+            self.menu_state.move_down(item_count);
+            if self.highlighted_item_selectable() {
+                break;
+            }
+        }
     }
 
+    /// Moves the highlight up, skipping past any dividers/sections/inactive items.
     pub fn navigate_up(&mut self) {
-        self.menu_state.move_up();
+        let item_count = self.current_node().iter().count();
+        for _ in 0..item_count {
+            self.menu_state.move_up(item_count);
+            if self.highlighted_item_selectable() {
+                break;
+            }
+        }
     }
 
-    pub fn select_item(&mut self) {}
+    fn highlighted_item_selectable(&self) -> bool {
+        self.current_node()
+            .iter()
+            .nth(self.menu_state.highlighted_item())
+            .map(|item| item.data().is_selectable())
+            .unwrap_or(false)
+    }
+
+    /// Moves `highlighted_item` forward to the first selectable row of `current_node`, if it
+    /// isn't already on one. Called whenever a level is freshly entered (`descend`, `navigate_back`)
+    /// and at the start of every `draw`, so a leading section/divider/inactive item is never
+    /// pre-selected when shown, the same
+    /// way `navigate_up`/`navigate_down`'s skip loop already avoids landing on one.
+    fn seek_first_selectable(&self) {
+        if self.highlighted_item_selectable() {
+            return;
+        }
+        if let Some(index) = self
+            .current_node()
+            .iter()
+            .position(|item| item.data().is_selectable())
+        {
+            self.menu_state.set_highlighted_item(index);
+        }
+    }
+
+    /// Leave the currently displayed submenu and return to its parent, if any.
+    pub fn navigate_back(&mut self) {
+        self.menu_state.navigate_back();
+        self.seek_first_selectable();
+    }
+
+    /// If the highlighted item is a selector, move it to its previous option.
+    pub fn navigate_left(&mut self) {
+        let highlighted = self.menu_state.highlighted_item();
+        if let Some(item) = self.current_node_mut().iter_mut().nth(highlighted) {
+            if let MenuItems::Selector(selector) = item.data_mut() {
+                selector.select_previous();
+            }
+        }
+    }
+
+    /// If the highlighted item is a selector, move it to its next option.
+    pub fn navigate_right(&mut self) {
+        let highlighted = self.menu_state.highlighted_item();
+        if let Some(item) = self.current_node_mut().iter_mut().nth(highlighted) {
+            if let MenuItems::Selector(selector) = item.data_mut() {
+                selector.select_next();
+            }
+        }
+    }
+
+    /// Activate the currently highlighted item: descending into a submenu, toggling a
+    /// checkbox's checked state, or selecting a radio item within its group.
+    pub fn select_item(&mut self) {
+        let highlighted = self.menu_state.highlighted_item();
+
+        let selected = self
+            .current_node()
+            .iter()
+            .nth(highlighted)
+            .map(|item| *item.data());
+
+        if let Some(MenuItems::Submenu(_)) = selected {
+            self.menu_state.descend(highlighted);
+            self.seek_first_selectable();
+            return;
+        }
+
+        let group = match selected {
+            Some(MenuItems::Radio(radio)) => Some(radio.group()),
+            _ => None,
+        };
+
+        if let Some(group) = group {
+            for item in self.current_node_mut().iter_mut() {
+                if let MenuItems::Radio(radio) = item.data_mut() {
+                    if radio.group() == group {
+                        radio.set_checked(false);
+                    }
+                }
+            }
+        }
+
+        if let Some(item) = self.current_node_mut().iter_mut().nth(highlighted) {
+            match item.data_mut() {
+                MenuItems::Checkbox(checkbox) => checkbox.toggle(),
+                MenuItems::Radio(radio) => radio.set_checked(true),
+                _ => {}
+            }
+        }
+    }
+
+    /// Resolves the submenu node currently being displayed by walking the navigation path
+    /// from the root, so a browsable multi-level menu can be rendered one level at a time.
+    fn current_node(&self) -> &Node<MenuItems<'a, C>> {
+        let mut node: &Node<MenuItems<'a, C>> = &self.menu_tree_root;
+        for &index in self.menu_state.path() {
+            match node.iter().nth(index) {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+        node
+    }
+
+    fn current_node_mut(&mut self) -> &mut Node<MenuItems<'a, C>> {
+        let mut node: &mut Node<MenuItems<'a, C>> = &mut self.menu_tree_root;
+        for &index in self.menu_state.path() {
+            match node.iter_mut().nth(index) {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+        node
+    }
 }
 
 impl<C> Drawable for Menu<'_, C>
@@ -97,10 +348,11 @@ where
     {
         let display_area = display.bounding_box();
         display.clear(self.menu_style.menu_background_color)?;
-        let header = self.menu_tree_root.data();
+        self.seek_first_selectable();
+        let current_node = self.current_node();
         let header_height = self.menu_style.heading_character_style.line_height();
         Text::with_baseline(
-            header.label(),
+            current_node.data().label(),
             Point::zero(),
             self.menu_style.heading_character_style,
             Baseline::Top,
@@ -109,27 +361,78 @@ where
 
         let mut remaining_item_area = display_area
             .resized_height(display_area.size().height - header_height, AnchorY::Bottom);
+        let item_viewport_area = remaining_item_area;
 
-        let menu_iter = self
-            .menu_tree_root
-            .iter()
-            .skip(self.menu_state.highlighted_item());
+        let total_items = current_node.iter().count();
+        let highlighted_item = self.menu_state.highlighted_item();
+        let menu_iter = current_node.iter().skip(highlighted_item);
 
+        let mut is_highlighted_item = true;
+        let mut items_drawn = 0usize;
         for menu_item in menu_iter {
             let item_height = menu_item.data().size().height;
             if item_height > remaining_item_area.size().height {
                 break;
             }
 
-            let mut item_display = display.cropped(&remaining_item_area);
+            menu_item.data().set_highlighted(is_highlighted_item);
+            is_highlighted_item = false;
+
+            let item_area = remaining_item_area
+                .resized_width(
+                    remaining_item_area.size().width - SCROLL_INDICATOR_WIDTH,
+                    AnchorX::Left,
+                )
+                .resized_height(item_height, AnchorY::Top);
+            let mut item_display = display.cropped(&item_area);
             menu_item.data().draw(&mut item_display)?;
+            items_drawn += 1;
 
+            let consumed_height = item_height + self.menu_style.item_spacing;
             remaining_item_area = remaining_item_area.resized_height(
-                remaining_item_area.size().height - item_height,
+                remaining_item_area.size().height.saturating_sub(consumed_height),
                 AnchorY::Bottom,
             );
         }
 
+        let show_up_indicator = highlighted_item > 0;
+        let show_down_indicator = highlighted_item + items_drawn < total_items;
+        if show_up_indicator || show_down_indicator {
+            let indicator_column = item_viewport_area.resized_width(
+                SCROLL_INDICATOR_WIDTH,
+                AnchorX::Right,
+            );
+            let filled_style = PrimitiveStyle::with_fill(self.menu_style.indicator_fill_color);
+
+            if show_up_indicator {
+                let up_area = indicator_column.resized_height(SCROLL_INDICATOR_WIDTH, AnchorY::Top);
+                let mut up_display = display.cropped(&up_area);
+                Triangle::new(
+                    Point::new(0, SCROLL_INDICATOR_WIDTH as i32),
+                    Point::new(SCROLL_INDICATOR_WIDTH as i32, SCROLL_INDICATOR_WIDTH as i32),
+                    Point::new((SCROLL_INDICATOR_WIDTH / 2) as i32, 0),
+                )
+                .into_styled(filled_style)
+                .draw(&mut up_display)?;
+            }
+
+            if show_down_indicator {
+                let down_area =
+                    indicator_column.resized_height(SCROLL_INDICATOR_WIDTH, AnchorY::Bottom);
+                let mut down_display = display.cropped(&down_area);
+                Triangle::new(
+                    Point::new(0, 0),
+                    Point::new(SCROLL_INDICATOR_WIDTH as i32, 0),
+                    Point::new(
+                        (SCROLL_INDICATOR_WIDTH / 2) as i32,
+                        SCROLL_INDICATOR_WIDTH as i32,
+                    ),
+                )
+                .into_styled(filled_style)
+                .draw(&mut down_display)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -143,6 +446,17 @@ where
     }
 }
 
+/// Controls how tall each item's row is, modelled after iced_aw's `ItemHeight`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ItemHeight {
+    /// Row height follows the measured height of the item's own content.
+    Dynamic,
+    /// Row height is at least this many pixels, growing further if the content needs more.
+    Uniform(u32),
+    /// Row height is exactly this many pixels, regardless of the content's measured height.
+    Static(u32),
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct MenuStyle<'a, C> {
     pub(crate) menu_background_color: C,
@@ -151,6 +465,8 @@ pub struct MenuStyle<'a, C> {
     pub(crate) indicator_fill_color: C,
     pub(crate) highlight_item_color: C,
     pub(crate) highlight_text_style: MonoTextStyle<'a, C>,
+    pub(crate) item_height: ItemHeight,
+    pub(crate) item_spacing: u32,
 }
 
 impl<'a, C> MenuStyle<'a, C>
@@ -164,6 +480,8 @@ where
         indicator_fill_color: C,
         highlight_item_color: C,
         highlight_text_style: MonoTextStyle<'a, C>,
+        item_height: ItemHeight,
+        item_spacing: u32,
     ) -> Self {
         Self {
             menu_background_color,
@@ -172,41 +490,178 @@ where
             indicator_fill_color,
             highlight_item_color,
             highlight_text_style,
+            item_height,
+            item_spacing,
         }
     }
 }
 
+/// How many levels deep a menu can be browsed. Fixed so the navigation path can live in a
+/// `heapless::Vec` rather than requiring an allocator.
+const MAX_MENU_DEPTH: usize = 8;
+
 struct MenuState {
-    highlighted_item: usize,
-    item_count: usize,
+    /// Index of the submenu entered at each level, from the root down to the displayed node.
+    path: heapless::Vec<usize, MAX_MENU_DEPTH>,
+    /// Kept in a `Cell` so `Menu::draw` (which only gets `&self`, per the `Drawable` trait it
+    /// implements) can still self-correct it to the first selectable row via
+    /// `Menu::seek_first_selectable`.
+    highlighted_item: Cell<usize>,
 }
 
 impl MenuState {
     pub fn new() -> Self {
         Self {
-            highlighted_item: 0,
-            item_count: 0,
+            path: heapless::Vec::new(),
+            highlighted_item: Cell::new(0),
         }
     }
-    pub fn update_item_count(&mut self, item_count: usize) {
-        self.item_count = item_count;
-    }
-    pub fn move_down(&mut self) {
-        self.highlighted_item += 1;
-        if self.highlighted_item > self.item_count {
-            self.highlighted_item = 0;
+
+    pub fn move_down(&mut self, item_count: usize) {
+        let mut next = self.highlighted_item.get() + 1;
+        if next >= item_count {
+            next = 0;
         }
+        self.highlighted_item.set(next);
     }
 
-    pub fn move_up(&mut self) {
-        if self.highlighted_item == 0 {
-            self.highlighted_item = self.item_count - 1;
-        } else {
-            self.highlighted_item -= 1;
-        }
+    pub fn move_up(&mut self, item_count: usize) {
+        let current = self.highlighted_item.get();
+        self.highlighted_item
+            .set(if current == 0 { item_count - 1 } else { current - 1 });
     }
 
     pub fn highlighted_item(&self) -> usize {
-        self.highlighted_item
+        self.highlighted_item.get()
+    }
+
+    pub fn set_highlighted_item(&self, highlighted_item: usize) {
+        self.highlighted_item.set(highlighted_item);
+    }
+
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// Descend into the submenu at `index` within the currently displayed level. A no-op if the
+    /// navigation path is already at `MAX_MENU_DEPTH`, leaving the current level's highlight
+    /// untouched rather than stranding it at the top of an unchanged list.
+    pub fn descend(&mut self, index: usize) {
+        if self.path.push(index).is_ok() {
+            self.highlighted_item.set(0);
+        }
+    }
+
+    /// Return to the parent level, if any.
+    pub fn navigate_back(&mut self) {
+        self.path.pop();
+        self.highlighted_item.set(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn test_style() -> MenuStyle<'static, BinaryColor> {
+        let character_style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+        MenuStyle::new(
+            BinaryColor::Off,
+            character_style,
+            character_style,
+            BinaryColor::On,
+            BinaryColor::On,
+            character_style,
+            ItemHeight::Dynamic,
+            0,
+        )
+    }
+
+    #[test]
+    fn navigate_down_skips_section_and_inactive_items() {
+        let mut menu = Menu::new("Root", test_style());
+        menu.add_checkbox("First");
+        menu.add_section("Section");
+        let inactive = menu.add_checkbox("Inactive");
+        menu.set_inactive(inactive, true);
+        menu.add_checkbox("Last");
+
+        assert_eq!(menu.menu_state.highlighted_item(), 0);
+
+        menu.navigate_down();
+
+        assert_eq!(menu.menu_state.highlighted_item(), 3);
+    }
+
+    #[test]
+    fn navigate_up_skips_divider() {
+        let mut menu = Menu::new("Root", test_style());
+        menu.add_checkbox("First");
+        menu.add_divider();
+        menu.add_checkbox("Last");
+
+        menu.navigate_down();
+        assert_eq!(menu.menu_state.highlighted_item(), 2);
+
+        menu.navigate_up();
+
+        assert_eq!(menu.menu_state.highlighted_item(), 0);
+    }
+
+    #[test]
+    fn move_down_wraps_to_zero_past_last_item() {
+        let mut state = MenuState::new();
+        state.move_down(3);
+        state.move_down(3);
+        state.move_down(3);
+        assert_eq!(state.highlighted_item(), 0);
+    }
+
+    #[test]
+    fn move_down_stays_in_bounds_for_single_item_node() {
+        let mut state = MenuState::new();
+        state.move_down(1);
+        assert_eq!(state.highlighted_item(), 0);
+        state.move_down(1);
+        assert_eq!(state.highlighted_item(), 0);
+    }
+
+    #[test]
+    fn move_up_wraps_to_last_item() {
+        let mut state = MenuState::new();
+        state.move_up(3);
+        assert_eq!(state.highlighted_item(), 2);
+    }
+
+    #[test]
+    fn descend_and_navigate_back_round_trip_through_path() {
+        let mut state = MenuState::new();
+        state.move_down(3);
+        state.descend(1);
+        assert_eq!(state.path(), &[1]);
+        assert_eq!(state.highlighted_item(), 0);
+
+        state.move_down(2);
+        state.navigate_back();
+        assert_eq!(state.path(), &[] as &[usize]);
+        assert_eq!(state.highlighted_item(), 0);
+    }
+
+    #[test]
+    fn descend_past_max_depth_leaves_state_untouched() {
+        let mut state = MenuState::new();
+        for level in 0..MAX_MENU_DEPTH {
+            state.descend(level);
+        }
+        state.move_down(5);
+        let highlighted_before = state.highlighted_item();
+        let path_before: heapless::Vec<usize, MAX_MENU_DEPTH> = state.path().iter().copied().collect();
+
+        state.descend(0);
+
+        assert_eq!(state.highlighted_item(), highlighted_before);
+        assert_eq!(state.path(), path_before.as_slice());
     }
 }